@@ -1,5 +1,11 @@
+use std::time::Duration;
+
 use crate::CustomSelect;
 
+/// The default quiet period an [`AsyncOptionFetcher`](super::prompt::AsyncOptionFetcher)-backed
+/// prompt waits after the last filter keystroke before sending a request.
+pub const DEFAULT_DEBOUNCE_INTERVAL: Duration = Duration::from_millis(150);
+
 /// Configuration settings used in the execution of a CustomSelectPrompt.
 #[derive(Copy, Clone, Debug)]
 pub struct CustomSelectConfig {
@@ -7,6 +13,9 @@ pub struct CustomSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// How long to wait after the last filter keystroke before sending an async fetch request.
+    /// Unused by synchronous [`OptionFetcher`](super::prompt::OptionFetcher)s.
+    pub debounce_interval: Duration,
 }
 
 impl<T> From<&CustomSelect<'_, T>> for CustomSelectConfig {
@@ -14,6 +23,7 @@ impl<T> From<&CustomSelect<'_, T>> for CustomSelectConfig {
         Self {
             vim_mode: value.vim_mode,
             page_size: value.page_size,
+            debounce_interval: value.debounce_interval,
         }
     }
 }
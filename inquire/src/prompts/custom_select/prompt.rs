@@ -1,4 +1,11 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{
+    cmp::Reverse,
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    time::{Duration, Instant},
+};
 
 use crate::{
     error::InquireResult,
@@ -25,15 +32,241 @@ pub trait OptionFetcher<T> {
     fn fetch(&self, input: &str, offset: usize, amount: usize) -> (Vec<T>, usize);
 }
 
+/// An [`OptionFetcher`] counterpart for option sources that need to go out of process, e.g. a
+/// REST autocomplete endpoint or a database lookup.
+///
+/// Unlike [`OptionFetcher`], `fetch` returns a future instead of blocking the caller, so
+/// [`CustomSelectPrompt`] can debounce keystrokes and keep rendering while a request is
+/// outstanding rather than freezing the UI until it resolves.
+pub trait AsyncOptionFetcher<T> {
+    fn fetch(
+        &self,
+        input: &str,
+        offset: usize,
+        amount: usize,
+    ) -> Pin<Box<dyn Future<Output = (Vec<T>, usize)> + Send>>;
+}
+
+type FetchFuture<T> = Pin<Box<dyn Future<Output = (Vec<T>, usize)> + Send>>;
+
+enum FetcherKind<T> {
+    Sync(Box<dyn OptionFetcher<T>>),
+    Async(Box<dyn AsyncOptionFetcher<T>>),
+}
+
+// A no-op `Waker` for polling `in_flight` from `poll`. Nothing here relies on wake
+// notifications: the (external) read loop is expected to call `poll` again on its own timeout
+// tick regardless of whether the future is ready yet.
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        raw_waker()
+    }
+    fn no_op(_: *const ()) {}
+
+    fn raw_waker() -> RawWaker {
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    unsafe { Waker::from_raw(raw_waker()) }
+}
+
+/// A single candidate accepted by a fuzzy-matching [`OptionFetcher`], pairing the original
+/// value with the byte indices of its [`Display`] representation that matched the filter, so
+/// callers can emphasize them when rendering.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch<T> {
+    pub value: T,
+    pub matched_indices: Vec<usize>,
+}
+
+/// Renders as plain text — [`FuzzyMatch::value`]'s own [`Display`] output, untouched. Backends
+/// (and anything else) that measure, pad, or truncate the rendered string by its length need
+/// this to stay plain; baking ANSI codes in here would throw that math off and ignore
+/// `NO_COLOR`. Use [`FuzzyMatch::highlighted_with`] to get the emphasized form instead.
+impl<T> Display for FuzzyMatch<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl<T> FuzzyMatch<T>
+where
+    T: Display,
+{
+    /// Applies `style` to each maximal run of [`FuzzyMatch::matched_indices`] in `value`'s
+    /// rendered text and leaves the rest untouched, the same way a backend already styles e.g.
+    /// the selected row through its own [`RenderConfig`](crate::ui::RenderConfig) rather than
+    /// baking escape codes into a `Display` impl. Wire this into a backend's option-rendering
+    /// path (or an [`OptionFormatter`]) to get the "highlight as you type" effect; `Display`
+    /// itself stays plain so nothing else has to account for embedded styling.
+    pub fn highlighted_with(&self, style: impl Fn(&str) -> String) -> String {
+        let candidate = self.value.to_string();
+        let mut result = String::with_capacity(candidate.len());
+        let mut run_start: Option<usize> = None;
+
+        for (index, ch) in candidate.char_indices() {
+            let is_match = self.matched_indices.binary_search(&index).is_ok();
+
+            if is_match {
+                run_start.get_or_insert(index);
+            } else {
+                if let Some(start) = run_start.take() {
+                    result.push_str(&style(&candidate[start..index]));
+                }
+                result.push(ch);
+            }
+        }
+
+        if let Some(start) = run_start {
+            result.push_str(&style(&candidate[start..]));
+        }
+
+        result
+    }
+}
+
+/// An [`OptionFetcher`] that filters and ranks an in-memory list of options by fuzzy
+/// subsequence matching against the typed filter, instead of requiring callers to hand-write
+/// their own fetcher.
+///
+/// A candidate is kept if every character of the filter appears in its [`Display`]
+/// representation in order (case-insensitive); it is scored by how tightly those characters
+/// cluster, and survivors are returned highest-scoring first.
+pub struct FuzzyOptionFetcher<T> {
+    options: Vec<T>,
+}
+
+impl<T> FuzzyOptionFetcher<T> {
+    pub fn new(options: Vec<T>) -> Self {
+        Self { options }
+    }
+}
+
+impl<T> OptionFetcher<FuzzyMatch<T>> for FuzzyOptionFetcher<T>
+where
+    T: Display + Clone,
+{
+    fn fetch(&self, input: &str, offset: usize, amount: usize) -> (Vec<FuzzyMatch<T>>, usize) {
+        let mut scored: Vec<(i64, usize, Vec<usize>)> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, option)| {
+                fuzzy_match(input, &option.to_string())
+                    .map(|(score, matched_indices)| (score, idx, matched_indices))
+            })
+            .collect();
+
+        scored.sort_by_key(|(score, idx, _)| (Reverse(*score), *idx));
+
+        let total_matches = scored.len();
+        let page = scored
+            .into_iter()
+            .skip(offset)
+            .take(amount)
+            .map(|(_, idx, matched_indices)| FuzzyMatch {
+                value: self.options[idx].clone(),
+                matched_indices,
+            })
+            .collect();
+
+        (page, total_matches)
+    }
+}
+
+// Bonus/penalty weights for `fuzzy_match` below. Tuned only to order matches sensibly
+// (consecutive runs and word-start hits first), not to any external scoring scheme.
+const FUZZY_CONSECUTIVE_BONUS: i64 = 8;
+const FUZZY_WORD_BOUNDARY_BONUS: i64 = 6;
+const FUZZY_GAP_PENALTY: i64 = 2;
+const FUZZY_LEADING_GAP_PENALTY: i64 = 1;
+
+// Matches `pattern` against `candidate` as a case-insensitive subsequence, returning the score
+// and the matched byte indices into `candidate`, or `None` if some character of `pattern` does
+// not appear in order.
+fn fuzzy_match(pattern: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    if pattern.is_empty() {
+        return Some((0, vec![]));
+    }
+
+    let pattern_lower: Vec<char> = pattern.to_lowercase().chars().collect();
+    // Byte offset of each char alongside its lowercased form, so a match can record where it
+    // actually sits in `candidate` (a byte index) rather than where it sits among `candidate`'s
+    // chars — those diverge as soon as `candidate` has a multi-byte character before the match.
+    let candidate_char_indices: Vec<(usize, char)> = candidate.char_indices().collect();
+    let candidate_chars: Vec<char> = candidate_char_indices.iter().map(|&(_, c)| c).collect();
+    let candidate_lower: Vec<char> = candidate_chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut matched_indices = Vec::with_capacity(pattern_lower.len());
+    let mut score: i64 = 0;
+    let mut pattern_idx = 0;
+    let mut prev_match: Option<usize> = None;
+
+    for (i, c) in candidate_lower.iter().enumerate() {
+        if pattern_idx >= pattern_lower.len() {
+            break;
+        }
+
+        if *c != pattern_lower[pattern_idx] {
+            continue;
+        }
+
+        score += match prev_match {
+            Some(prev) if prev + 1 == i => FUZZY_CONSECUTIVE_BONUS,
+            Some(prev) => -FUZZY_GAP_PENALTY * (i - prev - 1) as i64,
+            None => -FUZZY_LEADING_GAP_PENALTY * i as i64,
+        };
+
+        if is_fuzzy_word_boundary(&candidate_chars, i) {
+            score += FUZZY_WORD_BOUNDARY_BONUS;
+        }
+
+        matched_indices.push(candidate_char_indices[i].0);
+        prev_match = Some(i);
+        pattern_idx += 1;
+    }
+
+    if pattern_idx < pattern_lower.len() {
+        return None;
+    }
+
+    Some((score, matched_indices))
+}
+
+fn is_fuzzy_word_boundary(chars: &[char], index: usize) -> bool {
+    match index.checked_sub(1).map(|i| chars[i]) {
+        None => true,
+        Some(prev) => {
+            prev == '_'
+                || prev == '-'
+                || prev == ' '
+                || (prev.is_lowercase() && chars[index].is_uppercase())
+        }
+    }
+}
+
 pub struct CustomSelectPrompt<'a, T> {
     message: &'a str,
     config: CustomSelectConfig,
-    options_fetcher: Box<dyn OptionFetcher<T>>,
+    options_fetcher: FetcherKind<T>,
     fetched_options: Vec<T>,
     window: Window,
     help_message: Option<&'a str>,
     input: Input,
     formatter: OptionFormatter<'a, T>,
+    // Debounce/in-flight bookkeeping; only ever populated when `options_fetcher` is
+    // `FetcherKind::Async`.
+    pending_since: Option<Instant>,
+    request_token: u64,
+    in_flight: Option<(u64, FetchFuture<T>)>,
+    loading: bool,
 }
 
 impl<'a, T> CustomSelectPrompt<'a, T>
@@ -46,6 +279,7 @@ where
             config: CustomSelectConfig {
                 vim_mode: so.vim_mode,
                 page_size: so.page_size,
+                debounce_interval: so.debounce_interval,
             },
             fetched_options: vec![],
             window: Window {
@@ -54,16 +288,57 @@ where
                 total_length: 0,
                 cursor_index: so.starting_cursor,
             },
-            options_fetcher: so.options_fetcher,
+            options_fetcher: FetcherKind::Sync(so.options_fetcher),
             help_message: so.help_message,
             input: so
                 .starting_filter_input
                 .map(Input::new_with)
                 .unwrap_or_else(Input::new),
             formatter: so.formatter,
+            pending_since: None,
+            request_token: 0,
+            in_flight: None,
+            loading: false,
         })
     }
 
+    /// Builds a prompt backed by an [`AsyncOptionFetcher`] instead of a synchronous
+    /// [`OptionFetcher`]. Content changes to the filter input are debounced by
+    /// `config.debounce_interval` before a request is sent, and [`CustomSelectPrompt::poll`]
+    /// must be called periodically (e.g. by the read loop on its own timeout tick) to drive
+    /// outstanding requests to completion.
+    pub fn new_async(
+        message: &'a str,
+        config: CustomSelectConfig,
+        starting_cursor: usize,
+        options_fetcher: Box<dyn AsyncOptionFetcher<T>>,
+        help_message: Option<&'a str>,
+        starting_filter_input: Option<String>,
+        formatter: OptionFormatter<'a, T>,
+    ) -> Self {
+        Self {
+            message,
+            window: Window {
+                offset: starting_cursor,
+                window_length: config.page_size,
+                total_length: 0,
+                cursor_index: starting_cursor,
+            },
+            config,
+            fetched_options: vec![],
+            options_fetcher: FetcherKind::Async(options_fetcher),
+            help_message,
+            input: starting_filter_input
+                .map(Input::new_with)
+                .unwrap_or_else(Input::new),
+            formatter,
+            pending_since: None,
+            request_token: 0,
+            in_flight: None,
+            loading: false,
+        }
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         if self.window.total_length == 0 {
             return ActionResult::Clean;
@@ -102,6 +377,61 @@ where
         }
     }
 
+    fn move_page_up(&mut self) -> ActionResult {
+        if self.window.total_length == 0 {
+            return ActionResult::Clean;
+        }
+
+        let new_index = self.window.cursor_index.saturating_sub(self.window.window_length);
+        self.jump_to(new_index)
+    }
+
+    fn move_page_down(&mut self) -> ActionResult {
+        if self.window.total_length == 0 {
+            return ActionResult::Clean;
+        }
+
+        let new_index = self
+            .window
+            .cursor_index
+            .saturating_add(self.window.window_length)
+            .min(self.window.total_length - 1);
+        self.jump_to(new_index)
+    }
+
+    fn move_to_start(&mut self) -> ActionResult {
+        self.jump_to(0)
+    }
+
+    fn move_to_end(&mut self) -> ActionResult {
+        if self.window.total_length == 0 {
+            return ActionResult::Clean;
+        }
+
+        self.jump_to(self.window.total_length - 1)
+    }
+
+    // Sets cursor and offset together and returns early if the cursor did not move.
+    //
+    // This must run *before* `refetch` is called so that `refetch`'s own call to
+    // `update_cursor_position` is a no-op: `apply_fetch_result` clamps the cursor into
+    // `offset..=offset + window_length`, so with a stale offset it would pull the cursor back
+    // into the previous window instead of the one we're jumping to.
+    fn jump_to(&mut self, new_index: usize) -> ActionResult {
+        if new_index == self.window.cursor_index {
+            return ActionResult::Clean;
+        }
+
+        self.window.cursor_index = new_index;
+        self.window.offset = new_index.min(
+            self.window
+                .total_length
+                .saturating_sub(self.window.window_length),
+        );
+
+        ActionResult::NeedsRedraw
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.fetched_options
             .get(self.fetched_index_from_cursor_index())
@@ -123,12 +453,20 @@ where
     }
 
     fn refetch(&mut self) {
-        let (options, total_length) = self.options_fetcher.fetch(
-            self.input.content(),
-            self.window.offset,
-            self.window.window_length,
-        );
+        match &self.options_fetcher {
+            FetcherKind::Sync(fetcher) => {
+                let (options, total_length) = fetcher.fetch(
+                    self.input.content(),
+                    self.window.offset,
+                    self.window.window_length,
+                );
+                self.apply_fetch_result(options, total_length);
+            }
+            FetcherKind::Async(_) => self.start_async_fetch(),
+        }
+    }
 
+    fn apply_fetch_result(&mut self, options: Vec<T>, total_length: usize) {
         self.fetched_options = options;
         self.window.total_length = total_length;
 
@@ -140,6 +478,74 @@ where
                 .min(self.window.total_length.saturating_sub(1)),
         );
     }
+
+    // Records that the filter input changed so `poll` can send a request once the debounce
+    // interval has elapsed with no further edits, rather than fetching on every keystroke.
+    //
+    // Also invalidates whatever request is currently outstanding: it was fetching a now-stale
+    // query, so bumping `request_token` makes `poll` drop its response on arrival instead of
+    // racing the debounce timer, and clearing `in_flight` stops `poll` from spending a tick
+    // polling a future we no longer care about.
+    fn schedule_refetch(&mut self) {
+        self.pending_since = Some(Instant::now());
+        self.request_token += 1;
+        self.in_flight = None;
+    }
+
+    fn start_async_fetch(&mut self) {
+        let FetcherKind::Async(fetcher) = &self.options_fetcher else {
+            return;
+        };
+
+        self.request_token += 1;
+        let future = fetcher.fetch(
+            self.input.content(),
+            self.window.offset,
+            self.window.window_length,
+        );
+
+        self.in_flight = Some((self.request_token, future));
+        self.pending_since = None;
+        self.loading = true;
+    }
+
+    /// Drives any outstanding [`AsyncOptionFetcher`] request forward, and starts a new one if
+    /// the configured debounce interval has elapsed since the last filter keystroke.
+    ///
+    /// Must be called periodically by the surrounding read loop (e.g. on its own poll/timeout
+    /// tick) for an async-backed prompt to make progress; a no-op for synchronous fetchers. The
+    /// read loop reaches this through [`Prompt::poll`], which this prompt's `Prompt` impl
+    /// delegates to below — the loop only ever sees the trait method, never this inherent one.
+    pub fn poll(&mut self) -> ActionResult {
+        let mut result = ActionResult::Clean;
+
+        if let Some(pending_since) = self.pending_since {
+            if pending_since.elapsed() >= self.config.debounce_interval {
+                self.start_async_fetch();
+                result = ActionResult::NeedsRedraw;
+            }
+        }
+
+        if let Some((token, mut future)) = self.in_flight.take() {
+            let waker = noop_waker();
+            let mut cx = Context::from_waker(&waker);
+
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready((options, total_length)) => {
+                    // A stale response (the user kept typing and a newer request superseded
+                    // this one) is dropped rather than clobbering fresher results.
+                    if token == self.request_token {
+                        self.apply_fetch_result(options, total_length);
+                    }
+                    self.loading = false;
+                    result = ActionResult::NeedsRedraw;
+                }
+                Poll::Pending => self.in_flight = Some((token, future)),
+            }
+        }
+
+        result
+    }
 }
 
 impl<'a, Backend, T> Prompt<Backend> for CustomSelectPrompt<'a, T>
@@ -177,6 +583,10 @@ where
         Ok(answer)
     }
 
+    fn poll(&mut self) -> ActionResult {
+        CustomSelectPrompt::poll(self)
+    }
+
     fn handle(&mut self, action: CustomSelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             CustomSelectPromptAction::MoveUp => {
@@ -189,15 +599,34 @@ where
                 self.refetch();
                 result
             }
-            CustomSelectPromptAction::PageUp => todo!(),
-            CustomSelectPromptAction::PageDown => todo!(),
-            CustomSelectPromptAction::MoveToStart => todo!(),
-            CustomSelectPromptAction::MoveToEnd => todo!(),
+            CustomSelectPromptAction::PageUp => {
+                let result = self.move_page_up();
+                self.refetch();
+                result
+            }
+            CustomSelectPromptAction::PageDown => {
+                let result = self.move_page_down();
+                self.refetch();
+                result
+            }
+            CustomSelectPromptAction::MoveToStart => {
+                let result = self.move_to_start();
+                self.refetch();
+                result
+            }
+            CustomSelectPromptAction::MoveToEnd => {
+                let result = self.move_to_end();
+                self.refetch();
+                result
+            }
             CustomSelectPromptAction::FilterInput(input_action) => {
                 let result = self.input.handle(input_action);
 
                 if let InputActionResult::ContentChanged = result {
-                    self.refetch();
+                    match self.options_fetcher {
+                        FetcherKind::Sync(_) => self.refetch(),
+                        FetcherKind::Async(_) => self.schedule_refetch(),
+                    }
                 }
 
                 result.into()
@@ -228,10 +657,328 @@ where
 
         backend.render_options(page)?;
 
-        if let Some(help_message) = self.help_message {
+        if self.loading {
+            // Keep whatever was fetched previously on screen; only the help line changes to
+            // signal that a request is outstanding.
+            backend.render_help_message("Fetching options...")?;
+        } else if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A fetcher over a virtual `0..total_length` range, used to exercise paging without
+    // materializing the whole list up front.
+    struct RangeFetcher {
+        total_length: usize,
+    }
+
+    impl OptionFetcher<usize> for RangeFetcher {
+        fn fetch(&self, _input: &str, offset: usize, amount: usize) -> (Vec<usize>, usize) {
+            let end = (offset + amount).min(self.total_length);
+            let options = if offset >= end {
+                vec![]
+            } else {
+                (offset..end).collect()
+            };
+
+            (options, self.total_length)
+        }
+    }
+
+    fn prompt_with(total_length: usize, window_length: usize) -> CustomSelectPrompt<'static, usize> {
+        let mut prompt = CustomSelectPrompt {
+            message: "test",
+            config: CustomSelectConfig {
+                vim_mode: false,
+                page_size: window_length,
+                debounce_interval: Duration::from_millis(150),
+            },
+            options_fetcher: FetcherKind::Sync(Box::new(RangeFetcher { total_length })),
+            fetched_options: vec![],
+            window: Window {
+                offset: 0,
+                window_length,
+                total_length: 0,
+                cursor_index: 0,
+            },
+            help_message: None,
+            input: Input::new(),
+            formatter: &|a| a.to_string(),
+            pending_since: None,
+            request_token: 0,
+            in_flight: None,
+            loading: false,
+        };
+
+        prompt.refetch();
+
+        prompt
+    }
+
+    #[test]
+    fn move_to_end_fetches_and_highlights_the_last_option() {
+        let mut prompt = prompt_with(1_000, 10);
+
+        prompt.move_to_end();
+        prompt.refetch();
+
+        assert_eq!(prompt.window.cursor_index, 999);
+        assert_eq!(prompt.window.offset, 990);
+        assert_eq!(prompt.fetched_options.last(), Some(&999));
+        assert!(prompt.has_answer_highlighted());
+    }
+
+    #[test]
+    fn move_to_start_after_move_to_end_refetches_the_first_page() {
+        let mut prompt = prompt_with(1_000, 10);
+
+        prompt.move_to_end();
+        prompt.refetch();
+
+        prompt.move_to_start();
+        prompt.refetch();
+
+        assert_eq!(prompt.window.cursor_index, 0);
+        assert_eq!(prompt.window.offset, 0);
+        assert_eq!(prompt.fetched_options.first(), Some(&0));
+    }
+
+    #[test]
+    fn page_down_advances_by_a_full_window() {
+        let mut prompt = prompt_with(1_000, 10);
+
+        prompt.move_page_down();
+        prompt.refetch();
+
+        assert_eq!(prompt.window.cursor_index, 10);
+        assert_eq!(prompt.fetched_options.first(), Some(&10));
+    }
+
+    #[test]
+    fn page_down_clamps_to_the_last_option_near_the_end() {
+        let mut prompt = prompt_with(25, 10);
+
+        prompt.move_page_down();
+        prompt.refetch();
+        prompt.move_page_down();
+        prompt.refetch();
+        prompt.move_page_down();
+        prompt.refetch();
+
+        assert_eq!(prompt.window.cursor_index, 24);
+        assert_eq!(prompt.fetched_options.last(), Some(&24));
+    }
+
+    #[test]
+    fn page_up_clamps_to_the_first_option_near_the_start() {
+        let mut prompt = prompt_with(1_000, 10);
+
+        prompt.move_page_up();
+        prompt.refetch();
+
+        assert_eq!(prompt.window.cursor_index, 0);
+        assert_eq!(prompt.fetched_options.first(), Some(&0));
+    }
+
+    #[test]
+    fn fuzzy_option_fetcher_filters_out_non_subsequence_matches() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["apple", "banana", "grape"]);
+
+        let (options, total_matches) = fetcher.fetch("ap", 0, 10);
+
+        assert_eq!(total_matches, 2);
+        assert_eq!(
+            options.into_iter().map(|m| m.value).collect::<Vec<_>>(),
+            vec!["apple", "grape"]
+        );
+    }
+
+    #[test]
+    fn fuzzy_option_fetcher_ranks_consecutive_matches_higher_than_scattered_ones() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["a_b_c", "abc"]);
+
+        let (options, _) = fetcher.fetch("abc", 0, 10);
+
+        assert_eq!(options[0].value, "abc");
+    }
+
+    #[test]
+    fn fuzzy_option_fetcher_paginates_survivors() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["aa", "ab", "ac", "ad"]);
+
+        let (options, total_matches) = fetcher.fetch("a", 2, 2);
+
+        assert_eq!(total_matches, 4);
+        assert_eq!(options.len(), 2);
+    }
+
+    #[test]
+    fn fuzzy_option_fetcher_reports_matched_indices() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["apple"]);
+
+        let (options, _) = fetcher.fetch("ape", 0, 10);
+
+        assert_eq!(options[0].matched_indices, vec![0, 1, 4]);
+    }
+
+    #[test]
+    fn fuzzy_match_reports_byte_offsets_not_char_offsets_after_a_multi_byte_character() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["café bar"]);
+
+        let (options, _) = fetcher.fetch("bar", 0, 10);
+
+        // "é" is 2 bytes wide, so the char index of "bar" (5) and its byte offset (6) diverge;
+        // matched_indices must report the latter since it indexes into the UTF-8 string.
+        assert_eq!(options[0].matched_indices, vec![6, 7, 8]);
+        assert_eq!(
+            options[0].highlighted_with(|run| format!("[{run}]")),
+            "café [bar]"
+        );
+    }
+
+    #[test]
+    fn fuzzy_match_display_is_plain_text_with_no_embedded_styling() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["apple"]);
+
+        let (options, _) = fetcher.fetch("ape", 0, 10);
+
+        assert_eq!(options[0].to_string(), "apple");
+    }
+
+    #[test]
+    fn fuzzy_match_highlighted_with_wraps_each_matched_run() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["apple"]);
+
+        let (options, _) = fetcher.fetch("ape", 0, 10);
+
+        assert_eq!(
+            options[0].highlighted_with(|run| format!("[{run}]")),
+            "[ap]pl[e]"
+        );
+    }
+
+    #[test]
+    fn fuzzy_match_highlighted_with_is_a_no_op_with_no_filter() {
+        let fetcher = FuzzyOptionFetcher::new(vec!["apple"]);
+
+        let (options, _) = fetcher.fetch("", 0, 10);
+
+        assert_eq!(options[0].highlighted_with(|run| format!("[{run}]")), "apple");
+    }
+
+    // An async fetcher whose future is ready immediately, so debounce timing is the only thing
+    // under test here, not real request latency.
+    struct ReadyFetcher {
+        total_length: usize,
+    }
+
+    impl AsyncOptionFetcher<usize> for ReadyFetcher {
+        fn fetch(&self, _input: &str, offset: usize, amount: usize) -> FetchFuture<usize> {
+            let end = (offset + amount).min(self.total_length);
+            let options = if offset >= end {
+                vec![]
+            } else {
+                (offset..end).collect()
+            };
+            let total_length = self.total_length;
+
+            Box::pin(std::future::ready((options, total_length)))
+        }
+    }
+
+    fn async_prompt_with(total_length: usize, window_length: usize) -> CustomSelectPrompt<'static, usize> {
+        let config = CustomSelectConfig {
+            vim_mode: false,
+            page_size: window_length,
+            debounce_interval: Duration::ZERO,
+        };
+
+        CustomSelectPrompt::new_async(
+            "test",
+            config,
+            0,
+            Box::new(ReadyFetcher { total_length }),
+            None,
+            None,
+            &|a| a.to_string(),
+        )
+    }
+
+    #[test]
+    fn async_fetcher_does_not_fetch_immediately_on_filter_change() {
+        let mut prompt = async_prompt_with(100, 10);
+
+        prompt.schedule_refetch();
+
+        assert!(prompt.pending_since.is_some());
+        assert!(prompt.in_flight.is_none());
+        assert!(!prompt.loading);
+    }
+
+    #[test]
+    fn async_fetcher_fetches_once_the_debounce_interval_has_elapsed() {
+        let mut prompt = async_prompt_with(100, 10);
+
+        prompt.schedule_refetch();
+        let result = prompt.poll();
+
+        assert!(matches!(result, ActionResult::NeedsRedraw));
+        assert_eq!(prompt.window.total_length, 100);
+        assert!(!prompt.loading);
+        assert!(prompt.pending_since.is_none());
+    }
+
+    #[test]
+    fn async_fetcher_drops_a_stale_response_superseded_by_a_newer_request() {
+        let mut prompt = async_prompt_with(100, 10);
+
+        prompt.start_async_fetch();
+        let stale_token = prompt.request_token;
+        // A newer request (e.g. from another keystroke) takes over before the stale one is
+        // polled to completion.
+        prompt.start_async_fetch();
+
+        // Simulate the stale future finishing late, after it has already been superseded.
+        prompt.in_flight = Some((
+            stale_token,
+            Box::pin(std::future::ready((vec![1, 2, 3], 3))),
+        ));
+        prompt.poll();
+
+        assert_ne!(prompt.window.total_length, 3);
+    }
+
+    #[test]
+    fn schedule_refetch_invalidates_an_in_flight_request_immediately() {
+        let mut prompt = async_prompt_with(100, 10);
+
+        prompt.start_async_fetch();
+        let stale_token = prompt.request_token;
+        assert!(prompt.in_flight.is_some());
+
+        // A keystroke arrives while the previous request is still outstanding — the primary
+        // case async fetching exists for.
+        prompt.schedule_refetch();
+
+        assert!(prompt.in_flight.is_none());
+        assert_ne!(prompt.request_token, stale_token);
+
+        // Even if the stale future were still held onto and finished late, its result is not
+        // applied: with a zero debounce interval, `poll` first sends the pending request, which
+        // replaces the stale future before it is ever polled.
+        prompt.in_flight = Some((
+            stale_token,
+            Box::pin(std::future::ready((vec![1, 2, 3], 3))),
+        ));
+        prompt.poll();
+
+        assert_ne!(prompt.window.total_length, 3);
+    }
+}
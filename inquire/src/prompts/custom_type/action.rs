@@ -0,0 +1,41 @@
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
+
+use super::config::CustomTypeConfig;
+
+/// Set of actions for a CustomTypePrompt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CustomTypePromptAction {
+    /// Action on the value text input handler.
+    ValueInput(InputAction),
+    /// Increments the number under the cursor by `config.increment_step.step`.
+    Increment,
+    /// Decrements the number under the cursor by `config.increment_step.step`.
+    Decrement,
+    /// Increments the number under the cursor by `config.increment_step.page_step`.
+    PageIncrement,
+    /// Decrements the number under the cursor by `config.increment_step.page_step`.
+    PageDecrement,
+}
+
+impl InnerAction for CustomTypePromptAction {
+    type Config = CustomTypeConfig;
+
+    fn from_key(key: Key, _config: &CustomTypeConfig) -> Option<Self> {
+        let action = match key {
+            Key::Up(KeyModifiers::NONE) => Self::Increment,
+            Key::Down(KeyModifiers::NONE) => Self::Decrement,
+            Key::PageUp => Self::PageIncrement,
+            Key::PageDown => Self::PageDecrement,
+
+            key => match InputAction::from_key(key, &()) {
+                Some(action) => Self::ValueInput(action),
+                None => return None,
+            },
+        };
+
+        Some(action)
+    }
+}
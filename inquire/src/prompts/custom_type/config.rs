@@ -0,0 +1,18 @@
+use crate::CustomType;
+
+use super::increment::IncrementStep;
+
+/// Configuration settings used in the execution of a CustomTypePrompt.
+#[derive(Copy, Clone, Debug)]
+pub struct CustomTypeConfig {
+    /// Step sizes Up/Down/PageUp/PageDown adjust the number under the cursor by.
+    pub increment_step: IncrementStep,
+}
+
+impl<T> From<&CustomType<'_, T>> for CustomTypeConfig {
+    fn from(value: &CustomType<'_, T>) -> Self {
+        Self {
+            increment_step: value.increment_step,
+        }
+    }
+}
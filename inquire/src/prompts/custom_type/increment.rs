@@ -0,0 +1,201 @@
+//! Numeric increment/decrement support for `CustomType` prompts, so Up/Down (and
+//! PageUp/PageDown for larger steps) can adjust a value in place instead of only moving the
+//! text cursor.
+//!
+//! This module is self-contained: given the current input and cursor position, it locates the
+//! number the cursor is in or adjacent to, steps it by a configurable amount, and splices the
+//! result back in while preserving the rest of the string and the cursor's offset.
+
+/// Step sizes used by the increment subsystem: `step` for Up/Down, `page_step` for
+/// PageUp/PageDown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IncrementStep {
+    pub step: f64,
+    pub page_step: f64,
+}
+
+impl Default for IncrementStep {
+    fn default() -> Self {
+        Self {
+            step: 1.0,
+            page_step: 10.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct NumberSpan {
+    start: usize,
+    end: usize,
+}
+
+fn is_digit_or_dot(b: u8) -> bool {
+    b.is_ascii_digit() || b == b'.'
+}
+
+// Whether `b` could be part of a word/identifier immediately before a `-`, which distinguishes
+// a negative sign ("price: -3") from a separator ("sku-3").
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+// Finds the number the cursor is inside or touching, preferring the character immediately to
+// its left (the common case of the cursor sitting right after what was just typed).
+fn find_number_at_cursor(content: &str, cursor: usize) -> Option<NumberSpan> {
+    let bytes = content.as_bytes();
+    let cursor = cursor.min(bytes.len());
+
+    let anchor = if cursor > 0 && is_digit_or_dot(bytes[cursor - 1]) {
+        cursor - 1
+    } else if cursor < bytes.len() && is_digit_or_dot(bytes[cursor]) {
+        cursor
+    } else {
+        return None;
+    };
+
+    let mut start = anchor;
+    while start > 0 && is_digit_or_dot(bytes[start - 1]) {
+        start -= 1;
+    }
+    // A `-` immediately before the digit run is only a sign if it isn't itself trailing a word
+    // character, so "sku-3" increments the "3" instead of treating it as "-3".
+    if start > 0 && bytes[start - 1] == b'-' && !(start > 1 && is_identifier_byte(bytes[start - 2]))
+    {
+        start -= 1;
+    }
+
+    let mut end = anchor;
+    while end < bytes.len() && is_digit_or_dot(bytes[end]) {
+        end += 1;
+    }
+
+    if !content[start..end].chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(NumberSpan { start, end })
+}
+
+// Re-pads `replacement` with leading zeros to match `original`'s width, as long as it still
+// fits (the value didn't grow a digit), so "007" incrementing to 8 renders as "008". Values
+// with a decimal point, and originals starting with "-0", are returned without padding.
+fn preserve_leading_zeros(original: &str, replacement: String) -> String {
+    if replacement.contains('.') || !original.starts_with('0') || original.starts_with("-0") {
+        return replacement;
+    }
+
+    let negative = replacement.starts_with('-');
+    let digits = replacement.trim_start_matches('-');
+    let width = original.len() - usize::from(negative);
+
+    if digits.len() >= width {
+        return replacement;
+    }
+
+    format!(
+        "{}{digits:0>width$}",
+        if negative { "-" } else { "" },
+        digits = digits,
+        width = width
+    )
+}
+
+/// Adjusts the number at or adjacent to `cursor` in `content` by `delta`, preserving its
+/// decimal places and the cursor's offset from the end of the number.
+///
+/// Returns `None` (a no-op) if no parseable number is found at the cursor.
+pub fn increment_at_cursor(content: &str, cursor: usize, delta: f64) -> Option<(String, usize)> {
+    let span = find_number_at_cursor(content, cursor)?;
+    let text = &content[span.start..span.end];
+    let value: f64 = text.parse().ok()?;
+
+    let decimals = text.split_once('.').map(|(_, frac)| frac.len()).unwrap_or(0);
+    let replacement = preserve_leading_zeros(text, format!("{:.*}", decimals, value + delta));
+
+    let offset_from_end = (span.end - cursor.min(span.end)).min(text.len());
+
+    let mut new_content = String::with_capacity(content.len() - text.len() + replacement.len());
+    new_content.push_str(&content[..span.start]);
+    new_content.push_str(&replacement);
+    new_content.push_str(&content[span.end..]);
+
+    let new_cursor = (span.start + replacement.len()).saturating_sub(offset_from_end);
+
+    Some((new_content, new_cursor))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn increments_a_plain_integer() {
+        let (content, cursor) = increment_at_cursor("41", 2, 1.0).unwrap();
+
+        assert_eq!(content, "42");
+        assert_eq!(cursor, 2);
+    }
+
+    #[test]
+    fn decrements_using_a_negative_delta() {
+        let (content, cursor) = increment_at_cursor("10", 2, -1.0).unwrap();
+
+        assert_eq!(content, "9");
+        assert_eq!(cursor, 1);
+    }
+
+    #[test]
+    fn preserves_decimal_places() {
+        let (content, _) = increment_at_cursor("1.50", 4, 1.0).unwrap();
+
+        assert_eq!(content, "2.50");
+    }
+
+    #[test]
+    fn preserves_leading_zeros_while_the_width_allows_it() {
+        let (content, _) = increment_at_cursor("007", 3, 1.0).unwrap();
+
+        assert_eq!(content, "008");
+    }
+
+    #[test]
+    fn crosses_zero_into_negative() {
+        let (content, _) = increment_at_cursor("1", 1, -2.0).unwrap();
+
+        assert_eq!(content, "-1");
+    }
+
+    #[test]
+    fn splices_into_the_surrounding_text_and_keeps_relative_cursor_offset() {
+        let (content, cursor) = increment_at_cursor("price: 9 USD", 8, 1.0).unwrap();
+
+        assert_eq!(content, "price: 10 USD");
+        assert_eq!(cursor, 9);
+    }
+
+    #[test]
+    fn does_not_treat_a_hyphen_separator_as_a_sign() {
+        let (content, _) = increment_at_cursor("sku-3", 5, 1.0).unwrap();
+
+        assert_eq!(content, "sku-4");
+    }
+
+    #[test]
+    fn still_treats_a_hyphen_as_a_sign_when_not_preceded_by_a_word_character() {
+        let (content, _) = increment_at_cursor("price: -3", 9, 1.0).unwrap();
+
+        assert_eq!(content, "price: -2");
+    }
+
+    #[test]
+    fn is_a_no_op_without_a_parseable_number() {
+        assert_eq!(increment_at_cursor("no numbers here", 3, 1.0), None);
+    }
+
+    #[test]
+    fn page_step_is_a_larger_default_than_step() {
+        let steps = IncrementStep::default();
+
+        assert!(steps.page_step > steps.step);
+    }
+}
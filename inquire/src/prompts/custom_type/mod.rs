@@ -0,0 +1,9 @@
+mod action;
+mod config;
+mod increment;
+mod prompt;
+
+pub use action::*;
+pub use config::*;
+pub use increment::*;
+pub use prompt::*;
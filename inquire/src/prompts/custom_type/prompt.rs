@@ -0,0 +1,133 @@
+use crate::{
+    error::InquireResult,
+    formatter::StringFormatter,
+    input::{Input, InputActionResult},
+    prompts::prompt::{ActionResult, Prompt},
+    ui::TextBackend,
+    CustomType,
+};
+
+use super::{
+    action::CustomTypePromptAction,
+    config::CustomTypeConfig,
+    increment::increment_at_cursor,
+};
+
+/// Runtime state for a [`CustomType`] prompt: a single-line text input parsed into `T` on
+/// submit, where Up/Down/PageUp/PageDown adjust the number under the cursor in place (via
+/// [`increment_at_cursor`]) instead of only moving through it like every other key does.
+pub struct CustomTypePrompt<'a, T> {
+    message: &'a str,
+    config: CustomTypeConfig,
+    input: Input,
+    parser: &'a dyn Fn(&str) -> Result<T, String>,
+    formatter: StringFormatter<'a, T>,
+    error_message: Option<String>,
+}
+
+impl<'a, T> CustomTypePrompt<'a, T> {
+    pub fn new(co: &'a CustomType<'a, T>) -> InquireResult<Self> {
+        Ok(Self {
+            message: co.message,
+            config: CustomTypeConfig::from(co),
+            input: co
+                .default
+                .as_ref()
+                .map(|default| (co.formatter)(default))
+                .map(Input::new_with)
+                .unwrap_or_else(Input::new),
+            parser: co.parser,
+            formatter: co.formatter,
+            error_message: None,
+        })
+    }
+
+    // Adjusts the number under the cursor by `delta`, leaving the input untouched if the cursor
+    // isn't on or next to a parseable number.
+    fn increment(&mut self, delta: f64) -> ActionResult {
+        match increment_at_cursor(self.input.content(), self.input.cursor(), delta) {
+            Some((content, cursor)) => {
+                self.input = Input::new_with(content).with_cursor(cursor);
+                ActionResult::NeedsRedraw
+            }
+            None => ActionResult::Clean,
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for CustomTypePrompt<'a, T>
+where
+    Backend: TextBackend,
+{
+    type Config = CustomTypeConfig;
+    type InnerAction = CustomTypePromptAction;
+    type Output = T;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &CustomTypeConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &T) -> String {
+        (self.formatter)(answer)
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<T>> {
+        match (self.parser)(self.input.content()) {
+            Ok(value) => {
+                self.error_message = None;
+                Ok(Some(value))
+            }
+            Err(message) => {
+                self.error_message = Some(message);
+                Ok(None)
+            }
+        }
+    }
+
+    fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            CustomTypePromptAction::Increment => {
+                let step = self.config.increment_step.step;
+                self.increment(step)
+            }
+            CustomTypePromptAction::Decrement => {
+                let step = self.config.increment_step.step;
+                self.increment(-step)
+            }
+            CustomTypePromptAction::PageIncrement => {
+                let page_step = self.config.increment_step.page_step;
+                self.increment(page_step)
+            }
+            CustomTypePromptAction::PageDecrement => {
+                let page_step = self.config.increment_step.page_step;
+                self.increment(-page_step)
+            }
+            CustomTypePromptAction::ValueInput(input_action) => {
+                match self.input.handle(input_action) {
+                    InputActionResult::ContentChanged => ActionResult::NeedsRedraw,
+                    _ => ActionResult::Clean,
+                }
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        backend.render_text_prompt(self.message, &self.input)?;
+
+        if let Some(error_message) = &self.error_message {
+            backend.render_error_message(error_message)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,430 @@
+use std::fmt::Display;
+
+use crate::{
+    error::InquireResult,
+    formatter::OptionFormatter,
+    input::Input,
+    list_option::ListOption,
+    prompts::prompt::{ActionResult, Prompt},
+    ui::{Key, KeyModifiers, RenderConfig, SelectBackend},
+    utils::paginate,
+    InnerAction,
+};
+
+const DEFAULT_PAGE_SIZE: usize = 7;
+const DEFAULT_VIM_MODE: bool = false;
+const DEFAULT_STARTING_CURSOR: usize = 0;
+
+/// A single choice in an [`Expand`] prompt, identified by a mnemonic shortcut key instead of
+/// only by cursor position.
+#[derive(Clone, Debug)]
+pub struct ExpandOption<T> {
+    /// The key that selects this option directly, from either the collapsed or expanded view.
+    pub key: char,
+    /// The value returned if this option is chosen.
+    pub value: T,
+}
+
+impl<T> ExpandOption<T> {
+    pub fn new(key: char, value: T) -> Self {
+        Self { key, value }
+    }
+}
+
+/// Prompt for decisions with a handful of mnemonic choices (overwrite/cancel/diff/help),
+/// modeled after the "expand" question type from other prompt libraries.
+///
+/// By default the options are collapsed into a one-line `(ocah)` hint of their shortcut keys.
+/// Pressing `h` expands the hint into the full `key) label` list, which can then be browsed
+/// with the arrow keys like [`Select`](crate::Select). Pressing an option's own key selects it
+/// and submits it immediately from either view; this lets users who already know the shortcuts
+/// skip browsing entirely.
+pub struct Expand<'a, T> {
+    pub message: &'a str,
+    pub options: Vec<ExpandOption<T>>,
+    pub help_message: Option<&'a str>,
+    pub page_size: usize,
+    pub vim_mode: bool,
+    pub starting_cursor: usize,
+    pub formatter: OptionFormatter<'a, T>,
+    pub render_config: RenderConfig<'a>,
+}
+
+impl<'a, T> Expand<'a, T>
+where
+    T: Display,
+{
+    pub fn new(message: &'a str, options: Vec<ExpandOption<T>>) -> Self {
+        Self {
+            message,
+            options,
+            help_message: None,
+            page_size: DEFAULT_PAGE_SIZE,
+            vim_mode: DEFAULT_VIM_MODE,
+            starting_cursor: DEFAULT_STARTING_CURSOR,
+            formatter: &|a| a.value.to_string(),
+            render_config: RenderConfig::default(),
+        }
+    }
+
+    pub fn with_help_message(mut self, help_message: &'a str) -> Self {
+        self.help_message = Some(help_message);
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    pub fn with_starting_cursor(mut self, starting_cursor: usize) -> Self {
+        self.starting_cursor = starting_cursor;
+        self
+    }
+
+    pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
+        self.formatter = formatter;
+        self
+    }
+
+    pub fn prompt(self) -> InquireResult<T> {
+        self.raw_prompt().map(|answer| answer.value)
+    }
+
+    pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        let terminal = crate::terminal::get_default_terminal()?;
+        let mut backend = crate::ui::Backend::new(terminal, self.render_config)?;
+        let prompt = ExpandPrompt::new(self);
+
+        crate::prompts::prompt::prompt(prompt, &mut backend)
+    }
+}
+
+/// Config for an [`ExpandPrompt`]. Includes the configured shortcut keys so
+/// [`ExpandPromptAction::from_key`] can recognize them without the action layer needing direct
+/// access to the option list.
+#[derive(Clone, Debug)]
+pub struct ExpandConfig {
+    pub vim_mode: bool,
+    pub page_size: usize,
+    pub shortcuts: Vec<char>,
+}
+
+impl<T> From<&Expand<'_, T>> for ExpandConfig {
+    fn from(value: &Expand<'_, T>) -> Self {
+        Self {
+            vim_mode: value.vim_mode,
+            page_size: value.page_size,
+            shortcuts: value.options.iter().map(|o| o.key).collect(),
+        }
+    }
+}
+
+/// Set of actions for an [`ExpandPrompt`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExpandPromptAction {
+    /// Selects the option with this shortcut key and submits it immediately.
+    Shortcut(char),
+    /// Toggles between the collapsed one-line hint and the full option list.
+    ToggleHint,
+    /// Moves the cursor to the option above, only meaningful while expanded.
+    MoveUp,
+    /// Moves the cursor to the option below, only meaningful while expanded.
+    MoveDown,
+}
+
+impl InnerAction for ExpandPromptAction {
+    type Config = ExpandConfig;
+
+    fn from_key(key: Key, config: &ExpandConfig) -> Option<Self> {
+        if let Key::Char(c, KeyModifiers::NONE) = key {
+            if config.shortcuts.contains(&c) {
+                return Some(Self::Shortcut(c));
+            }
+
+            // `h` is reserved for expanding the hint, same as the question type it is modeled
+            // after, unless an option already claims it as its own shortcut.
+            if c == 'h' {
+                return Some(Self::ToggleHint);
+            }
+        }
+
+        if config.vim_mode {
+            let action = match key {
+                Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
+                Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                _ => None,
+            };
+
+            if action.is_some() {
+                return action;
+            }
+        }
+
+        match key {
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
+                Some(Self::MoveUp)
+            }
+            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => {
+                Some(Self::MoveDown)
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct ExpandPrompt<'a, T> {
+    message: &'a str,
+    config: ExpandConfig,
+    options: Vec<ExpandOption<T>>,
+    expanded: bool,
+    cursor_index: usize,
+    help_message: Option<&'a str>,
+    formatter: OptionFormatter<'a, T>,
+    // `SelectBackend::render_select_prompt` renders a filter input; Expand has none, so this
+    // is created once and never updated.
+    input: Input,
+}
+
+impl<'a, T> ExpandPrompt<'a, T>
+where
+    T: Display,
+{
+    pub fn new(eo: Expand<'a, T>) -> Self {
+        Self {
+            message: eo.message,
+            config: ExpandConfig::from(&eo),
+            cursor_index: eo.starting_cursor.min(eo.options.len().saturating_sub(1)),
+            options: eo.options,
+            expanded: false,
+            help_message: eo.help_message,
+            formatter: eo.formatter,
+            input: Input::new(),
+        }
+    }
+
+    fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
+        if self.options.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        let len = self.options.len();
+        let qty = qty % len;
+        let new_index = (self.cursor_index + len - qty) % len;
+        self.set_cursor(new_index)
+    }
+
+    fn move_cursor_down(&mut self, qty: usize) -> ActionResult {
+        if self.options.is_empty() {
+            return ActionResult::Clean;
+        }
+
+        let len = self.options.len();
+        let new_index = (self.cursor_index + qty) % len;
+        self.set_cursor(new_index)
+    }
+
+    fn set_cursor(&mut self, new_index: usize) -> ActionResult {
+        if new_index == self.cursor_index {
+            ActionResult::Clean
+        } else {
+            self.cursor_index = new_index;
+            ActionResult::NeedsRedraw
+        }
+    }
+
+    // Unlike arrow-key movement, a shortcut key is the user directly naming their answer, so a
+    // match submits immediately instead of merely moving the cursor and waiting for Enter.
+    fn jump_to_shortcut(&mut self, key: char) -> ActionResult {
+        match self.options.iter().position(|o| o.key == key) {
+            Some(index) => {
+                self.cursor_index = index;
+                ActionResult::Submit
+            }
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn toggle_hint(&mut self) -> ActionResult {
+        self.expanded = !self.expanded;
+        ActionResult::NeedsRedraw
+    }
+
+    fn get_final_answer(&mut self) -> ListOption<T> {
+        let index = self.cursor_index;
+        let value = self.options.swap_remove(index).value;
+
+        ListOption::new(index, value)
+    }
+
+    fn try_submit(&mut self) -> Option<ListOption<T>> {
+        if self.options.is_empty() {
+            None
+        } else {
+            Some(self.get_final_answer())
+        }
+    }
+}
+
+impl<'a, Backend, T> Prompt<Backend> for ExpandPrompt<'a, T>
+where
+    Backend: SelectBackend,
+    T: Display,
+{
+    type Config = ExpandConfig;
+    type InnerAction = ExpandPromptAction;
+    type Output = ListOption<T>;
+
+    fn message(&self) -> &str {
+        self.message
+    }
+
+    fn config(&self) -> &ExpandConfig {
+        &self.config
+    }
+
+    fn format_answer(&self, answer: &ListOption<T>) -> String {
+        (self.formatter)(answer.as_ref())
+    }
+
+    fn setup(&mut self) -> InquireResult<()> {
+        Ok(())
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
+        Ok(self.try_submit())
+    }
+
+    fn handle(&mut self, action: ExpandPromptAction) -> InquireResult<ActionResult> {
+        let result = match action {
+            ExpandPromptAction::Shortcut(key) => self.jump_to_shortcut(key),
+            ExpandPromptAction::ToggleHint => self.toggle_hint(),
+            ExpandPromptAction::MoveUp if self.expanded => self.move_cursor_up(1),
+            ExpandPromptAction::MoveDown if self.expanded => self.move_cursor_down(1),
+            ExpandPromptAction::MoveUp | ExpandPromptAction::MoveDown => ActionResult::Clean,
+        };
+
+        Ok(result)
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()> {
+        let prompt_line = if self.expanded {
+            self.message.to_string()
+        } else {
+            let mut hint: String = self.options.iter().map(|o| o.key).collect();
+            // `h` toggles the expanded view unless an option claims it, so it is listed as well.
+            if !self.config.shortcuts.contains(&'h') {
+                hint.push('h');
+            }
+            format!("{} ({})", self.message, hint)
+        };
+
+        backend.render_select_prompt(&prompt_line, &self.input)?;
+
+        if self.expanded {
+            let list_options: Vec<ListOption<&T>> = self
+                .options
+                .iter()
+                .enumerate()
+                .map(|(i, o)| ListOption::new(i, &o.value))
+                .collect();
+            let page = paginate(self.config.page_size, &list_options, Some(self.cursor_index));
+
+            backend.render_options(page)?;
+        }
+
+        if let Some(help_message) = self.help_message {
+            backend.render_help_message(help_message)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn prompt_with(options: Vec<ExpandOption<&'static str>>) -> ExpandPrompt<'static, &'static str> {
+        let config = ExpandConfig {
+            vim_mode: false,
+            page_size: 7,
+            shortcuts: options.iter().map(|o| o.key).collect(),
+        };
+
+        ExpandPrompt {
+            message: "test",
+            config,
+            cursor_index: 0,
+            options,
+            expanded: false,
+            help_message: None,
+            formatter: &|a| a.value.to_string(),
+            input: Input::new(),
+        }
+    }
+
+    #[test]
+    fn shortcut_jumps_to_and_submits_the_matching_option() {
+        let mut prompt = prompt_with(vec![
+            ExpandOption::new('o', "overwrite"),
+            ExpandOption::new('c', "cancel"),
+        ]);
+
+        let result = prompt.jump_to_shortcut('c');
+
+        assert_eq!(result, ActionResult::Submit);
+        assert_eq!(prompt.cursor_index, 1);
+
+        let answer = prompt.try_submit().unwrap();
+        assert_eq!(answer.value, "cancel");
+    }
+
+    #[test]
+    fn shortcut_for_an_unknown_key_is_a_no_op() {
+        let mut prompt = prompt_with(vec![ExpandOption::new('o', "overwrite")]);
+
+        let result = prompt.jump_to_shortcut('z');
+
+        assert_eq!(result, ActionResult::Clean);
+        assert_eq!(prompt.cursor_index, 0);
+    }
+
+    #[test]
+    fn toggle_hint_flips_expanded_state() {
+        let mut prompt = prompt_with(vec![ExpandOption::new('o', "overwrite")]);
+
+        assert!(!prompt.expanded);
+        prompt.toggle_hint();
+        assert!(prompt.expanded);
+        prompt.toggle_hint();
+        assert!(!prompt.expanded);
+    }
+
+    #[test]
+    fn cursor_wraps_around_past_the_last_and_first_option() {
+        let mut prompt = prompt_with(vec![
+            ExpandOption::new('o', "overwrite"),
+            ExpandOption::new('c', "cancel"),
+            ExpandOption::new('a', "abort"),
+        ]);
+
+        prompt.move_cursor_up(1);
+        assert_eq!(prompt.cursor_index, 2);
+
+        prompt.move_cursor_down(1);
+        assert_eq!(prompt.cursor_index, 0);
+    }
+
+    #[test]
+    fn submit_with_no_options_returns_none() {
+        let mut prompt = prompt_with(vec![]);
+
+        assert!(prompt.try_submit().is_none());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::error::InquireResult;
+
+/// Outcome of handling a single input action, telling the read loop what to do before reading
+/// the next key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActionResult {
+    /// Nothing changed; no redraw needed.
+    Clean,
+    /// State changed; redraw before reading the next key.
+    NeedsRedraw,
+    /// The action itself is the final answer (e.g. a shortcut key in an [`Expand`
+    /// prompt](crate::Expand)) — submit immediately instead of waiting for a separate
+    /// confirmation key.
+    Submit,
+}
+
+/// Shared behavior every interactive prompt implements so a single read loop can drive any of
+/// them against a given backend.
+pub trait Prompt<Backend> {
+    /// Configuration snapshot `Self::InnerAction::from_key` is evaluated against.
+    type Config;
+    /// The action type produced from raw key input for this prompt.
+    type InnerAction;
+    /// The answer type produced once the prompt is done.
+    type Output;
+
+    fn message(&self) -> &str;
+
+    fn config(&self) -> &Self::Config;
+
+    fn format_answer(&self, answer: &Self::Output) -> String;
+
+    fn setup(&mut self) -> InquireResult<()>;
+
+    /// Tries to produce a final answer from the prompt's current state, e.g. on Enter.
+    /// Returns `Ok(None)` if the current state isn't a valid answer yet.
+    fn submit(&mut self) -> InquireResult<Option<Self::Output>>;
+
+    fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
+
+    /// Gives prompts that do background work between keystrokes (e.g. a debounced async fetch
+    /// in [`CustomSelectPrompt`](crate::CustomSelectPrompt)) a chance to make progress.
+    ///
+    /// The read loop calls this on every timeout tick, in between waiting for key events, so a
+    /// prompt that has nothing to do here simply keeps the default no-op.
+    fn poll(&mut self) -> ActionResult {
+        ActionResult::Clean
+    }
+
+    fn render(&self, backend: &mut Backend) -> InquireResult<()>;
+}